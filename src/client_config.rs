@@ -0,0 +1,81 @@
+use crate::Error;
+use reqwest::{Certificate, Client, ClientBuilder, Proxy};
+use std::time::Duration;
+
+/// Configuration for the underlying [`reqwest::Client`] used by the script clients.
+///
+/// Lets advanced users tune request/connect timeouts, enable transfer compression, route
+/// through a proxy, or trust an additional root certificate - all relevant when talking to an
+/// on-prem FileMaker server behind corporate TLS or a restrictive network.
+#[derive(Debug, Clone, Default)]
+pub struct ClientConfig {
+    request_timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    compression: bool,
+    proxy_url: Option<String>,
+    root_certificate: Option<Vec<u8>>,
+}
+
+impl ClientConfig {
+    /// Creates an empty configuration: no timeouts, no proxy, no additional root certificate,
+    /// and gzip and brotli transfer compression disabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the per-request timeout.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the connection timeout.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Enables gzip and brotli transfer compression.
+    pub fn with_compression(mut self, compression: bool) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Routes all requests through the given proxy URL.
+    pub fn with_proxy(mut self, proxy_url: impl Into<String>) -> Self {
+        self.proxy_url = Some(proxy_url.into());
+        self
+    }
+
+    /// Trusts an additional root certificate, given as DER or PEM encoded bytes.
+    pub fn with_root_certificate(mut self, certificate: impl Into<Vec<u8>>) -> Self {
+        self.root_certificate = Some(certificate.into());
+        self
+    }
+
+    pub(crate) fn build_client(&self) -> Result<Client, Error> {
+        let mut builder = ClientBuilder::new()
+            .gzip(self.compression)
+            .brotli(self.compression);
+
+        if let Some(timeout) = self.request_timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        if let Some(ref proxy_url) = self.proxy_url {
+            builder = builder.proxy(Proxy::all(proxy_url)?);
+        }
+
+        if let Some(ref certificate) = self.root_certificate {
+            let certificate = Certificate::from_pem(certificate)
+                .or_else(|_| Certificate::from_der(certificate))?;
+            builder = builder.add_root_certificate(certificate);
+        }
+
+        Ok(builder.build()?)
+    }
+}
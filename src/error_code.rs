@@ -0,0 +1,101 @@
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+
+/// A well-known FileMaker Server error code.
+///
+/// FileMaker reports errors as small integers, returned as strings by the Data API and as
+/// numbers in an OData script result. This enum gives the common ones a name so callers can
+/// match on them instead of hardcoding magic numbers; anything not covered here round-trips
+/// through [`FileMakerErrorCode::Unknown`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileMakerErrorCode {
+    /// `0` - No error.
+    NoError,
+    /// `101` - Record is missing.
+    RecordIsMissing,
+    /// `102` - Field is missing.
+    FieldIsMissing,
+    /// `212` - Invalid user account and/or password.
+    InvalidUserAccountOrPassword,
+    /// `401` - No records match the request.
+    NoRecordsMatch,
+    /// `500` - Date value does not meet validation entry options.
+    DateValidationFailed,
+    /// `501` - Time value does not meet validation entry options.
+    TimeValidationFailed,
+    /// `502` - Number value does not meet validation entry options.
+    NumberValidationFailed,
+    /// `503` - Value in field is not within the range specified in validation entry options.
+    ValueNotInRange,
+    /// `504` - Value in field is not a valid value for the validation entry options.
+    ValueNotValidOption,
+    /// `952` - Invalid FileMaker Data API token.
+    InvalidDataApiToken,
+    /// Any FileMaker error code not explicitly modeled above.
+    Unknown(i64),
+}
+
+impl FileMakerErrorCode {
+    /// Maps a raw FileMaker error code into its named variant.
+    pub fn from_code(code: i64) -> Self {
+        match code {
+            0 => Self::NoError,
+            101 => Self::RecordIsMissing,
+            102 => Self::FieldIsMissing,
+            212 => Self::InvalidUserAccountOrPassword,
+            401 => Self::NoRecordsMatch,
+            500 => Self::DateValidationFailed,
+            501 => Self::TimeValidationFailed,
+            502 => Self::NumberValidationFailed,
+            503 => Self::ValueNotInRange,
+            504 => Self::ValueNotValidOption,
+            952 => Self::InvalidDataApiToken,
+            other => Self::Unknown(other),
+        }
+    }
+
+    /// Returns the raw FileMaker error code for this variant.
+    pub fn as_i64(&self) -> i64 {
+        match self {
+            Self::NoError => 0,
+            Self::RecordIsMissing => 101,
+            Self::FieldIsMissing => 102,
+            Self::InvalidUserAccountOrPassword => 212,
+            Self::NoRecordsMatch => 401,
+            Self::DateValidationFailed => 500,
+            Self::TimeValidationFailed => 501,
+            Self::NumberValidationFailed => 502,
+            Self::ValueNotInRange => 503,
+            Self::ValueNotValidOption => 504,
+            Self::InvalidDataApiToken => 952,
+            Self::Unknown(code) => *code,
+        }
+    }
+}
+
+impl fmt::Display for FileMakerErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_i64())
+    }
+}
+
+impl From<i64> for FileMakerErrorCode {
+    fn from(code: i64) -> Self {
+        Self::from_code(code)
+    }
+}
+
+impl<'de> Deserialize<'de> for FileMakerErrorCode {
+    /// The Data API reports error codes as strings (e.g. `"401"`), so this parses the string
+    /// before mapping it to a named variant.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        let code: i64 = raw.parse().map_err(DeError::custom)?;
+
+        Ok(Self::from_code(code))
+    }
+}
@@ -6,8 +6,39 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use url::Url;
 
+pub mod client_config;
 pub mod data_api;
+pub mod error_code;
 pub mod odata_api;
+pub mod retry;
+pub mod token_store;
+
+pub use client_config::ClientConfig;
+pub use error_code::FileMakerErrorCode;
+pub use retry::RetryPolicy;
+pub use token_store::{FileTokenStore, MemoryTokenStore, Token, TokenStore};
+use std::sync::Arc;
+
+/// Emits a `tracing` debug event when the `tracing` feature is enabled, and is a no-op
+/// otherwise. Never pass credentials or token values to this macro.
+macro_rules! trace_debug {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    };
+}
+
+/// Emits a `tracing` warn event when the `tracing` feature is enabled, and is a no-op
+/// otherwise. Never pass credentials or token values to this macro.
+macro_rules! trace_warn {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::warn!($($arg)*);
+    };
+}
+
+pub(crate) use trace_debug;
+pub(crate) use trace_warn;
 
 #[async_trait]
 pub trait ScriptClient {
@@ -85,7 +116,10 @@ pub enum Error {
     FileMaker(FileMakerError),
 
     #[error("FileMaker script returned an error")]
-    ScriptFailure { code: i64, data: String },
+    ScriptFailure {
+        code: FileMakerErrorCode,
+        data: String,
+    },
 
     #[error("FileMaker did not respond with an access token")]
     MissingAccessToken,
@@ -99,7 +133,7 @@ pub enum Error {
 
 #[derive(Debug, Deserialize)]
 pub struct FileMakerError {
-    pub code: String,
+    pub code: FileMakerErrorCode,
     pub message: String,
 }
 
@@ -114,6 +148,7 @@ pub struct Connection {
     password: String,
     port: Option<u16>,
     disable_tls: bool,
+    retry_policy: Option<Arc<RetryPolicy>>,
 }
 
 impl Connection {
@@ -141,6 +176,7 @@ impl Connection {
             password: password.into(),
             port: None,
             disable_tls: false,
+            retry_policy: None,
         }
     }
 
@@ -155,6 +191,28 @@ impl Connection {
         self.disable_tls = disable_tls;
         self
     }
+
+    /// Configures a [`RetryPolicy`] that script clients built from this connection will use to
+    /// transparently retry transient failures (dropped connections, 5xx responses, `429`s, and
+    /// expired Data API tokens) with exponential backoff and jitter.
+    ///
+    /// By default, no retry policy is configured and clients fail immediately on the first
+    /// error, matching the previous behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fm_script_client::Connection;
+    /// use fm_script_client::retry::RetryPolicy;
+    /// use std::time::Duration;
+    ///
+    /// let connection = Connection::new("example.com", "test_sb", "foo", "bar")
+    ///     .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(200), Duration::from_secs(5), 2.0));
+    /// ```
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(Arc::new(retry_policy));
+        self
+    }
 }
 
 impl TryFrom<Url> for Connection {
@@ -191,6 +249,7 @@ impl TryFrom<Url> for Connection {
             password: decode(url.password().ok_or_else(|| Error::InvalidConnectionUrl)?)?,
             port: url.port(),
             disable_tls: url.scheme() == "http",
+            retry_policy: None,
         })
     }
 }
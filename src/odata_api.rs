@@ -1,9 +1,12 @@
-use crate::{Connection, Error, FileMakerError, ScriptClient};
+use crate::{
+    trace_warn, ClientConfig, Connection, Error, FileMakerError, FileMakerErrorCode, ScriptClient,
+};
 use async_trait::async_trait;
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::sync::Arc;
 use url::Url;
 
@@ -31,9 +34,142 @@ impl ODataApiScriptClient {
     /// );
     /// ```
     pub fn new(connection: Connection) -> Self {
+        Self::new_with_client(connection, Client::new())
+    }
+
+    /// Creates a new OData API script client, building its `reqwest::Client` from a
+    /// [`ClientConfig`].
+    ///
+    /// Use this to control request/connect timeouts, transfer compression, proxying, or an
+    /// additional trusted root certificate.
+    pub fn new_with_config(connection: Connection, config: ClientConfig) -> Result<Self, Error> {
+        Ok(Self::new_with_client(connection, config.build_client()?))
+    }
+
+    /// Creates a new OData API script client using a prebuilt [`reqwest::Client`].
+    ///
+    /// Use this if you need full control over the client, such as injecting your own
+    /// connection pool shared across multiple clients.
+    pub fn new_with_client(connection: Connection, client: Client) -> Self {
         Self {
             connection: Arc::new(connection),
-            client: Client::new(),
+            client,
+        }
+    }
+
+    /// Executes multiple scripts in a single round-trip using OData's `$batch` endpoint.
+    ///
+    /// Scripts are run in the given order, but results are reported independently: a failure
+    /// in one operation does not prevent the others from being reported. The returned `Vec`
+    /// preserves the order of `scripts`.
+    pub async fn execute_batch<T, P, S>(
+        &self,
+        scripts: Vec<(S, Option<P>)>,
+    ) -> Result<Vec<Result<T, Error>>, Error>
+    where
+        T: DeserializeOwned,
+        P: Serialize + Send + Sync,
+        S: Into<String>,
+    {
+        let mut url = Url::parse(&format!(
+            "{}://{}/fmi/odata/v4/{}/$batch",
+            if self.connection.disable_tls {
+                "http"
+            } else {
+                "https"
+            },
+            self.connection.hostname,
+            self.connection.database,
+        ))?;
+
+        if let Some(port) = self.connection.port {
+            let _ = url.set_port(Some(port));
+        }
+
+        let requests: Vec<_> = scripts
+            .into_iter()
+            .enumerate()
+            .map(|(index, (script_name, parameter))| BatchRequestOperation {
+                id: index.to_string(),
+                method: "POST",
+                url: format!("Script.{}", script_name.into()),
+                body: RequestBody {
+                    script_parameter_value: parameter,
+                },
+            })
+            .collect();
+        let operation_count = requests.len();
+
+        let body = BatchRequestBody { requests };
+
+        let response = self
+            .client
+            .post(url)
+            .basic_auth(&self.connection.username, Some(&self.connection.password))
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .json(&body)
+            .send()
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return match response.json::<ErrorResponseBody>().await {
+                Ok(result) => {
+                    trace_warn!(code = %result.error.code, "FileMaker returned an error response");
+                    Err(Error::FileMaker(result.error))
+                }
+                Err(_) => Err(Error::UnknownResponse(status)),
+            };
+        }
+
+        let batch_response: BatchResponseBody = response.json().await?;
+        let mut responses_by_id: HashMap<String, BatchResponseOperation> = batch_response
+            .responses
+            .into_iter()
+            .map(|operation| (operation.id.clone(), operation))
+            .collect();
+
+        Ok((0..operation_count)
+            .map(|index| match responses_by_id.remove(&index.to_string()) {
+                Some(operation) => Self::result_from_batch_operation(operation),
+                None => Err(Error::UnknownResponse(status)),
+            })
+            .collect())
+    }
+
+    fn result_from_batch_operation<T: DeserializeOwned>(
+        operation: BatchResponseOperation,
+    ) -> Result<T, Error> {
+        let status = StatusCode::from_u16(operation.status)
+            .map_err(|_| Error::UnknownResponse(StatusCode::INTERNAL_SERVER_ERROR))?;
+
+        if status.is_success() {
+            let result: ResponseBody = match operation.body {
+                Some(body) => serde_json::from_value(body)?,
+                None => return Err(Error::UnknownResponse(status)),
+            };
+
+            if result.script_result.code != 0 {
+                return Err(Error::ScriptFailure {
+                    code: FileMakerErrorCode::from_code(result.script_result.code),
+                    data: result.script_result.result_parameter.to_string(),
+                });
+            }
+
+            return Ok(serde_json::from_value(result.script_result.result_parameter)?);
+        }
+
+        match operation.body {
+            Some(body) => match serde_json::from_value::<ErrorResponseBody>(body) {
+                Ok(result) => {
+                    trace_warn!(code = %result.error.code, "FileMaker returned an error response");
+                    Err(Error::FileMaker(result.error))
+                }
+                Err(_) => Err(Error::UnknownResponse(status)),
+            },
+            None => Err(Error::UnknownResponse(status)),
         }
     }
 }
@@ -63,13 +199,50 @@ struct ErrorResponseBody {
     error: FileMakerError,
 }
 
+#[derive(Debug, Serialize)]
+struct BatchRequestBody<P> {
+    requests: Vec<BatchRequestOperation<P>>,
+}
+
+#[derive(Debug, Serialize)]
+struct BatchRequestOperation<P> {
+    id: String,
+    method: &'static str,
+    url: String,
+    body: RequestBody<P>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchResponseBody {
+    responses: Vec<BatchResponseOperation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchResponseOperation {
+    id: String,
+    status: u16,
+    #[serde(default)]
+    body: Option<Value>,
+}
+
 #[async_trait]
 impl ScriptClient for ODataApiScriptClient {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip(self, script_name, parameter),
+            fields(database = %self.connection.database, script_name = tracing::field::Empty, status = tracing::field::Empty)
+        )
+    )]
     async fn execute<T: DeserializeOwned, P: Serialize + Send + Sync>(
         &self,
         script_name: impl Into<String> + Send,
         parameter: Option<P>,
     ) -> Result<T, Error> {
+        let script_name = script_name.into();
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("script_name", script_name.as_str());
+
         let mut url = Url::parse(&format!(
             "{}://{}/fmi/odata/v4/{}/Script.{}",
             if self.connection.disable_tls {
@@ -79,7 +252,7 @@ impl ScriptClient for ODataApiScriptClient {
             },
             self.connection.hostname,
             self.connection.database,
-            script_name.into(),
+            script_name,
         ))?;
 
         if let Some(port) = self.connection.port {
@@ -90,35 +263,79 @@ impl ScriptClient for ODataApiScriptClient {
             script_parameter_value: parameter,
         };
 
-        let response = self
-            .client
-            .post(url)
-            .basic_auth(&self.connection.username, Some(&self.connection.password))
-            .header("Content-Type", "application/json")
-            .header("Accept", "application/json")
-            .json(&body)
-            .send()
-            .await?;
+        let policy = self.connection.retry_policy.clone();
+        let max_attempts = policy.as_ref().map_or(1, |policy| policy.max_attempts);
+        let mut attempt = 0;
 
-        let status = response.status();
+        loop {
+            let result = self
+                .client
+                .post(url.clone())
+                .basic_auth(&self.connection.username, Some(&self.connection.password))
+                .header("Content-Type", "application/json")
+                .header("Accept", "application/json")
+                .json(&body)
+                .send()
+                .await;
 
-        if status.is_success() {
-            let result: ResponseBody = response.json().await?;
+            let response = match result {
+                Ok(response) => response,
+                Err(err) => {
+                    if attempt + 1 < max_attempts {
+                        attempt += 1;
+                        trace_warn!(attempt, error = %err, "request failed, retrying");
 
-            if result.script_result.code != 0 {
-                return Err(Error::ScriptFailure {
-                    code: result.script_result.code,
-                    data: result.script_result.result_parameter.to_string(),
-                });
+                        if let Some(ref policy) = policy {
+                            tokio::time::sleep(policy.delay_for_attempt(attempt - 1)).await;
+                        }
+
+                        continue;
+                    }
+
+                    return Err(Error::Request(err));
+                }
+            };
+
+            let status = response.status();
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("status", status.as_u16());
+
+            if status.is_success() {
+                let result: ResponseBody = response.json().await?;
+
+                if result.script_result.code != 0 {
+                    trace_warn!(code = result.script_result.code, "FileMaker script returned an error");
+
+                    return Err(Error::ScriptFailure {
+                        code: FileMakerErrorCode::from_code(result.script_result.code),
+                        data: result.script_result.result_parameter.to_string(),
+                    });
+                }
+
+                let result: T = serde_json::from_value(result.script_result.result_parameter)?;
+                return Ok(result);
             }
 
-            let result: T = serde_json::from_value(result.script_result.result_parameter)?;
-            return Ok(result);
-        }
+            let retryable = status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS;
+
+            if retryable && attempt + 1 < max_attempts {
+                attempt += 1;
+                trace_warn!(attempt, %status, "request unsuccessful, retrying");
+
+                if let Some(ref policy) = policy {
+                    tokio::time::sleep(policy.delay_for_attempt(attempt - 1)).await;
+                }
+
+                continue;
+            }
 
-        match response.json::<ErrorResponseBody>().await {
-            Ok(result) => Err(Error::FileMaker(result.error)),
-            Err(_) => Err(Error::UnknownResponse(status)),
+            return match response.json::<ErrorResponseBody>().await {
+                Ok(result) => {
+                    trace_warn!(code = %result.error.code, "FileMaker returned an error response");
+                    Err(Error::FileMaker(result.error))
+                }
+                Err(_) => Err(Error::UnknownResponse(status)),
+            };
         }
     }
 }
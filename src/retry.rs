@@ -0,0 +1,62 @@
+use rand::Rng;
+use std::time::Duration;
+
+/// Governs how [`crate::ScriptClient`] implementations recover from transient failures.
+///
+/// When a request fails with a retryable error, the delay before the next attempt is
+/// computed as `min(max_delay, base_delay * multiplier^attempt)`, and full jitter is then
+/// applied by sleeping a random duration in `[0, delay)`. This spreads out retries from
+/// many concurrent callers instead of having them all hammer the server in lockstep.
+///
+/// A client without a configured policy does not retry at all, preserving the previous
+/// behavior of failing immediately.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub(crate) max_attempts: u32,
+    pub(crate) base_delay: Duration,
+    pub(crate) max_delay: Duration,
+    pub(crate) multiplier: f64,
+}
+
+impl RetryPolicy {
+    /// Creates a new retry policy.
+    ///
+    /// `max_attempts` includes the initial attempt, so `3` means the request is tried up to
+    /// three times in total (the original attempt plus two retries).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fm_script_client::retry::RetryPolicy;
+    /// use std::time::Duration;
+    ///
+    /// let policy = RetryPolicy::new(3, Duration::from_millis(200), Duration::from_secs(5), 2.0);
+    /// ```
+    pub fn new(max_attempts: u32, base_delay: Duration, max_delay: Duration, multiplier: f64) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+            max_delay,
+            multiplier,
+        }
+    }
+
+    pub(crate) fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let exponential = self.base_delay.as_secs_f64() * self.multiplier.powi(attempt as i32);
+        let capped = exponential.min(self.max_delay.as_secs_f64()).max(0.0);
+
+        if capped == 0.0 {
+            return Duration::ZERO;
+        }
+
+        let jittered = rand::thread_rng().gen_range(0.0..capped);
+        Duration::from_secs_f64(jittered)
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three total attempts, starting at 200ms and doubling up to a 10s ceiling.
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(200), Duration::from_secs(10), 2.0)
+    }
+}
@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tokio::sync::Mutex;
+
+/// A FileMaker Data API session token together with its absolute expiry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Token {
+    pub token: String,
+    pub expiry: DateTime<Utc>,
+}
+
+impl Token {
+    /// Returns whether this token is still within its validity window.
+    pub fn is_valid(&self) -> bool {
+        Utc::now() < self.expiry
+    }
+}
+
+/// Persists the [`Token`] used by [`crate::data_api::DataApiScriptClient`].
+///
+/// The default, used when no store is configured, keeps the token in memory for the lifetime
+/// of the client. Implement this trait to, for example, persist the token to disk so a
+/// short-lived CLI invocation doesn't re-authenticate against FileMaker on every run.
+#[async_trait]
+pub trait TokenStore: Send + Sync {
+    /// Loads the currently stored token, if any.
+    async fn load(&self) -> Option<Token>;
+
+    /// Persists a freshly acquired token.
+    async fn save(&self, token: &Token);
+
+    /// Forgets the currently stored token.
+    async fn clear(&self);
+}
+
+/// Keeps the token in memory for the lifetime of the store. This is the default used by
+/// [`crate::data_api::DataApiScriptClient`].
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    token: Mutex<Option<Token>>,
+}
+
+impl MemoryTokenStore {
+    /// Creates a new, empty in-memory token store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TokenStore for MemoryTokenStore {
+    async fn load(&self) -> Option<Token> {
+        self.token.lock().await.clone()
+    }
+
+    async fn save(&self, token: &Token) {
+        *self.token.lock().await = Some(token.clone());
+    }
+
+    async fn clear(&self) {
+        *self.token.lock().await = None;
+    }
+}
+
+/// Persists the token as JSON in a file, so it survives process restarts.
+///
+/// # Examples
+///
+/// ```
+/// use fm_script_client::token_store::FileTokenStore;
+///
+/// let store = FileTokenStore::new("/tmp/fm-script-client-token.json");
+/// ```
+#[derive(Debug)]
+pub struct FileTokenStore {
+    path: PathBuf,
+}
+
+impl FileTokenStore {
+    /// Creates a new file-backed token store at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl TokenStore for FileTokenStore {
+    async fn load(&self) -> Option<Token> {
+        let contents = tokio::fs::read_to_string(&self.path).await.ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    async fn save(&self, token: &Token) {
+        if let Ok(contents) = serde_json::to_string(token) {
+            let _ = tokio::fs::write(&self.path, contents).await;
+        }
+    }
+
+    async fn clear(&self) {
+        let _ = tokio::fs::remove_file(&self.path).await;
+    }
+}